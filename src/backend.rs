@@ -0,0 +1,142 @@
+use anyhow::anyhow;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// Storage abstraction shared by the directory and tar implementations.
+///
+/// The explode visitors and the implode deserializers are written against this
+/// trait so the type-inference code is identical whether the exploded layout
+/// lives as loose files on disk or as entries inside a single `.tar` archive.
+pub trait Backend {
+    /// Write a leaf value (or a sidecar file) at `path`.
+    fn write_leaf(&mut self, path: &Path, bytes: &[u8]) -> anyhow::Result<()>;
+    /// List the immediate children of the directory at `path`. Returns `Err`
+    /// when `path` is a leaf rather than a directory.
+    fn read_dir(&self, path: &Path) -> anyhow::Result<Vec<PathBuf>>;
+    /// Read the bytes of the leaf at `path`.
+    fn read_file(&self, path: &Path) -> anyhow::Result<Vec<u8>>;
+}
+
+/// [`Backend`] backed by loose files and directories on the real filesystem.
+pub struct DirBackend;
+
+impl Backend for DirBackend {
+    fn write_leaf(&mut self, path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(fs::write(path, bytes)?)
+    }
+
+    fn read_dir(&self, path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        Ok(fs::read_dir(path)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect())
+    }
+
+    fn read_file(&self, path: &Path) -> anyhow::Result<Vec<u8>> {
+        Ok(fs::read(path)?)
+    }
+}
+
+/// [`Backend`] backed by an in-memory set of tar entries.
+///
+/// Entry paths are stored relative to `root` (so they read `foo/bar/0` in the
+/// archive), while the visitors keep addressing values by their rooted path;
+/// `root` is stripped on write and re-joined on read to bridge the two.
+pub struct TarBackend {
+    root: PathBuf,
+    entries: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl TarBackend {
+    /// Create an empty backend whose entries are rooted at `root` (used by
+    /// `explode` before the archive is flushed with [`TarBackend::into_writer`]).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        TarBackend {
+            root: root.into(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Load an archive from `reader`, addressing its entries relative to `root`
+    /// (used by `implode`).
+    pub fn from_reader(root: impl Into<PathBuf>, reader: impl Read) -> anyhow::Result<Self> {
+        let mut archive = tar::Archive::new(reader);
+        let mut entries = BTreeMap::new();
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path = entry.path()?.into_owned();
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes)?;
+            entries.insert(path, bytes);
+        }
+
+        Ok(TarBackend {
+            root: root.into(),
+            entries,
+        })
+    }
+
+    /// Serialize every leaf collected so far into a tar archive on `writer`.
+    pub fn into_writer(self, writer: impl Write) -> anyhow::Result<()> {
+        let mut builder = tar::Builder::new(writer);
+        for (path, bytes) in self.entries.iter() {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(bytes.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, path, bytes.as_slice())?;
+        }
+        builder.finish()?;
+
+        Ok(())
+    }
+
+    /// Address a rooted path relative to the archive root.
+    fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.root).unwrap_or(path)
+    }
+}
+
+impl Backend for TarBackend {
+    fn write_leaf(&mut self, path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+        self.entries
+            .insert(self.relative(path).to_path_buf(), bytes.to_vec());
+        Ok(())
+    }
+
+    fn read_dir(&self, path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let prefix = self.relative(path);
+        let mut children = BTreeSet::new();
+        for key in self.entries.keys() {
+            if let Ok(rest) = key.strip_prefix(prefix) {
+                if let Some(component) = rest.components().next() {
+                    children.insert(prefix.join(component.as_os_str()));
+                }
+            }
+        }
+        if children.is_empty() {
+            return Err(anyhow!("Not a directory in archive: {:?}", path));
+        }
+
+        Ok(children
+            .into_iter()
+            .map(|child| self.root.join(child))
+            .collect())
+    }
+
+    fn read_file(&self, path: &Path) -> anyhow::Result<Vec<u8>> {
+        self.entries
+            .get(self.relative(path))
+            .cloned()
+            .ok_or_else(|| anyhow!("No such entry in archive: {:?}", path))
+    }
+}
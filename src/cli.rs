@@ -1,8 +1,8 @@
-use clap::Clap;
+use clap::Parser;
 use std::str::FromStr;
 
 /// Tool for converting TOML files to a set key/value files/folders
-#[derive(Clap)]
+#[derive(Parser)]
 #[clap(version = "0.1", author = "Terence Lee <hone02@gmail.com>")]
 pub struct Cli {
     /// 'explode' take a TOML file and convert to a filesystem layout.
@@ -13,14 +13,59 @@ pub struct Cli {
     pub source: String,
     /// Path to where the output is written
     pub destination: String,
+    /// Layout of the exploded representation: 'dir' for loose files, 'tar' for
+    /// a single archive.
+    #[clap(long, default_value = "dir", possible_values=&["dir", "tar"])]
+    pub format: Format,
+    /// Document format of the source (on explode) and destination (on implode).
+    #[clap(long, default_value = "toml", possible_values=&["toml", "json", "yaml"])]
+    pub doc_format: DocFormat,
 }
 
-#[derive(Clap)]
+#[derive(Clone)]
 pub enum Command {
     Explode,
     Implode,
 }
 
+#[derive(Clone)]
+pub enum Format {
+    Dir,
+    Tar,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Format, Self::Err> {
+        match input {
+            "dir" => Ok(Format::Dir),
+            "tar" => Ok(Format::Tar),
+            _ => Err("Invalid Format"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum DocFormat {
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl FromStr for DocFormat {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<DocFormat, Self::Err> {
+        match input {
+            "toml" => Ok(DocFormat::Toml),
+            "json" => Ok(DocFormat::Json),
+            "yaml" => Ok(DocFormat::Yaml),
+            _ => Err("Invalid Document Format"),
+        }
+    }
+}
+
 impl FromStr for Command {
     type Err = &'static str;
 
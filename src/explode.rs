@@ -1,77 +1,281 @@
-use serde::ser::Serialize;
-use std::{fs, path::Path};
+use crate::backend::Backend;
+use std::path::Path;
+use toml_edit::{Array, ArrayOfTables, Decor, Document, InlineTable, Item, Table, Value};
 
-/// Leaf node visitor method for serializing non-collection `toml::Value`s into a string on disk.
-/// # Examples
-/// ```
-/// use explodesh::explode;
-/// use tempfile::NamedTempFile;
-/// let file = NamedTempFile::new().unwrap();
-/// explode::visit_serialize(toml::Value::String(String::from("hello")), file.path());
-/// assert_eq!("\"hello\"", std::fs::read_to_string(file.path()).unwrap());
-/// ```
-pub fn visit_serialize(value: impl Serialize, path: impl AsRef<Path>) -> anyhow::Result<()> {
-    Ok(fs::write(path, toml::to_string(&value)?)?)
-}
+/// Hidden directory, written alongside each exploded table, that holds the
+/// per-key decor (comments/whitespace) captured from the source document.
+pub(crate) const META_DIR: &str = ".meta";
+/// File, written alongside each exploded table, listing the table's keys in
+/// document order so `implode` can rebuild them deterministically rather than
+/// relying on OS-dependent `fs::read_dir` ordering.
+pub(crate) const ORDER_FILE: &str = ".order";
+/// Zero-byte sentinel marking a directory as an array, so `implode` need not
+/// guess the collection kind from the (possibly ambiguous) entry names.
+pub(crate) const ARRAY_SENTINEL: &str = ".array";
+/// Zero-byte sentinel marking a directory as a table.
+pub(crate) const TABLE_SENTINEL: &str = ".table";
+/// File, written alongside the root table, holding the document's trailing
+/// trivia (comments/blank lines after the last key) so it survives a round trip.
+pub(crate) const TRAILER_FILE: &str = ".trailer";
 
-/// Visitor method for serializing `toml::Value::Table` variant on disk.
-/// # Examples
-/// ```
-/// use explodesh::explode;
-/// use std::fs;
-/// use tempfile::tempdir;
+/// Visitor entry point for a whole `toml_edit::Document`.
 ///
-/// let dir = tempdir().unwrap();
-/// let mut table = toml::value::Table::new();
-/// table.insert(String::from("foo"), toml::Value::String(String::from("hello")));
-/// table.insert(String::from("bar"), toml::Value::String(String::from("world")));
-/// explode::visit_table(&table, dir.path());
-///
-/// assert_eq!("\"hello\"", fs::read_to_string(dir.path().join("foo")).unwrap());
-/// assert_eq!("\"world\"", fs::read_to_string(dir.path().join("bar")).unwrap());
-/// ```
-pub fn visit_table(table: &toml::value::Table, path: impl AsRef<Path>) -> anyhow::Result<()> {
-    fs::create_dir_all(&path)?;
-    for (key, val) in table.iter() {
-        visit_value(val, path.as_ref().join(key))?
+/// It explodes the root table as usual and additionally captures the document
+/// trailer — the comment/whitespace block trailing the last key — into a
+/// [`TRAILER_FILE`] sidecar, which [`visit_table`] alone cannot see because it
+/// only walks per-key decor.
+pub fn visit_document(
+    backend: &mut dyn Backend,
+    doc: &Document,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    visit_table(backend, doc.as_table(), &path)?;
+    let trailer = doc.trailing().as_str().unwrap_or("");
+    if !trailer.is_empty() {
+        backend.write_leaf(&path.as_ref().join(TRAILER_FILE), trailer.as_bytes())?;
     }
 
     Ok(())
 }
 
-/// Visitor method for serializing `toml::Value::Array` variant on disk.
+/// Leaf node visitor method for serializing a non-collection `toml_edit::Value`
+/// into a string through `backend`. Only the scalar is written; for a table
+/// entry the surrounding decor is captured by the parent table (see
+/// [`visit_table`]), while the decor of an array element is not kept.
+///
+/// The leaf file name gains a type-tag extension matching the value's variant
+/// (`greeting.str`, `port.int`, ...) so `implode` can pin the type instead of
+/// re-guessing it and mis-classifying values like `1.0` or `02139`.
 /// # Examples
 /// ```
-/// use explodesh::explode;
-/// use std::fs;
+/// use explodesh::{backend::DirBackend, explode};
 /// use tempfile::tempdir;
-///
 /// let dir = tempdir().unwrap();
-/// let array = vec!["foo", "bar", "baz"]
-///         .into_iter()
-///         .map(|s| toml::Value::String(String::from(s)))
-///         .collect::<Vec<toml::Value>>();
-/// explode::visit_array(&array, dir.path());
-///
-/// assert_eq!("\"foo\"", fs::read_to_string(dir.path().join("0")).unwrap());
-/// assert_eq!("\"bar\"", fs::read_to_string(dir.path().join("1")).unwrap());
-/// assert_eq!("\"baz\"", fs::read_to_string(dir.path().join("2")).unwrap());
+/// let value = toml_edit::value("hello").into_value().unwrap();
+/// explode::visit_serialize(&mut DirBackend, &value, dir.path().join("greeting"));
+/// assert_eq!("\"hello\"", std::fs::read_to_string(dir.path().join("greeting.str")).unwrap());
 /// ```
-pub fn visit_array(array: &toml::value::Array, path: impl AsRef<Path>) -> anyhow::Result<()> {
+pub fn visit_serialize(
+    backend: &mut dyn Backend,
+    value: &Value,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    // `Value`'s `Display` includes its own decor (leading space, trailing
+    // same-line comment, ...); strip it so the leaf file holds just the scalar.
+    // For a table entry the decor is captured separately in the sidecar (see
+    // [`visit_table`]).
+    let path = tagged_path(path.as_ref(), value);
+    backend.write_leaf(&path, bare_scalar(value).as_bytes())
+}
+
+/// Render `value` without its surrounding decor, so a trailing same-line comment
+/// ends up in the sidecar rather than leaking into the leaf file.
+fn bare_scalar(value: &Value) -> String {
+    let mut value = value.clone();
+    let decor = value.decor_mut();
+    decor.set_prefix("");
+    decor.set_suffix("");
+    value.to_string().trim().to_string()
+}
+
+/// Append the type-tag extension for `value`'s variant to `path`. The extension
+/// is added to (never replaces) the existing file name so keys that contain
+/// dots survive.
+fn tagged_path(path: &Path, value: &Value) -> std::path::PathBuf {
+    let tag = match value {
+        Value::String(_) => "str",
+        Value::Integer(_) => "int",
+        Value::Float(_) => "float",
+        Value::Boolean(_) => "bool",
+        Value::Datetime(_) => "datetime",
+        // Collections are never serialized as leaves.
+        Value::Array(_) | Value::InlineTable(_) => return path.to_path_buf(),
+    };
+    match path.file_name() {
+        Some(name) => path.with_file_name(format!("{}.{}", name.to_string_lossy(), tag)),
+        None => path.to_path_buf(),
+    }
+}
+
+/// Visitor method for serializing a `toml_edit::Table` through `backend`.
+///
+/// Besides the child value for each entry, the table's directory gains a
+/// [`TABLE_SENTINEL`] marking it as a table, an [`ORDER_FILE`] recording the key
+/// order as it appeared in the document, and a [`META_DIR`] holding one file per
+/// key that has leading or trailing decor (keys without decor get no file).
+/// Together they let an explode/implode cycle reproduce comments, blank lines
+/// and key ordering verbatim.
+pub fn visit_table(
+    backend: &mut dyn Backend,
+    table: &Table,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    backend.write_leaf(&path.as_ref().join(TABLE_SENTINEL), b"")?;
+    write_order(backend, table.iter().map(|(key, _)| key), &path)?;
+    for (key, item) in table.iter() {
+        let (prefix, suffix) = item_decor(table.key_decor(key), item);
+        write_meta(backend, key, &prefix, &suffix, &path)?;
+        visit_value(backend, item, path.as_ref().join(key))?;
+    }
+
+    Ok(())
+}
+
+/// Visitor method for serializing a `toml_edit::InlineTable` (`{ a = 1 }`)
+/// through `backend`. It shares the same layout as a standard [`visit_table`].
+pub fn visit_inline_table(
+    backend: &mut dyn Backend,
+    table: &InlineTable,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    backend.write_leaf(&path.as_ref().join(TABLE_SENTINEL), b"")?;
+    write_order(backend, table.iter().map(|(key, _)| key), &path)?;
+    for (key, value) in table.iter() {
+        let (prefix, suffix) = value_decor(table.key_decor(key), value);
+        write_meta(backend, key, &prefix, &suffix, &path)?;
+        visit_serialize_or_recurse(backend, value, path.as_ref().join(key))?;
+    }
+
+    Ok(())
+}
+
+/// Visitor method for serializing a `toml_edit::Array` (`[1, 2, 3]`) through
+/// `backend`. Each element is written to a file named by its index.
+pub fn visit_array(
+    backend: &mut dyn Backend,
+    array: &Array,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    backend.write_leaf(&path.as_ref().join(ARRAY_SENTINEL), b"")?;
     for (i, val) in array.iter().enumerate() {
-        visit_value(val, path.as_ref().join(i.to_string()))?
+        visit_serialize_or_recurse(backend, val, path.as_ref().join(i.to_string()))?;
     }
 
     Ok(())
 }
 
-/// Visitor for serializing `toml::Value`
-pub fn visit_value(value: &toml::Value, path: impl AsRef<Path>) -> anyhow::Result<()> {
-    match value {
-        toml::Value::Table(table) => visit_table(&table, path)?,
-        toml::Value::Array(array) => visit_array(&array, path)?,
-        val => visit_serialize(val, path)?,
+/// Visitor method for serializing a `toml_edit::ArrayOfTables` (`[[servers]]`)
+/// through `backend`. Each table is written to a directory named by its index.
+pub fn visit_array_of_tables(
+    backend: &mut dyn Backend,
+    array: &ArrayOfTables,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    backend.write_leaf(&path.as_ref().join(ARRAY_SENTINEL), b"")?;
+    for (i, table) in array.iter().enumerate() {
+        visit_table(backend, table, path.as_ref().join(i.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Visitor for serializing a `toml_edit::Item`.
+pub fn visit_value(
+    backend: &mut dyn Backend,
+    item: &Item,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    match item {
+        Item::Table(table) => visit_table(backend, table, path)?,
+        Item::ArrayOfTables(array) => visit_array_of_tables(backend, array, path)?,
+        Item::Value(value) => visit_serialize_or_recurse(backend, value, path)?,
+        Item::None => {}
     }
 
     Ok(())
 }
+
+/// Dispatch a `toml_edit::Value` to the collection visitors when it nests, and
+/// to [`visit_serialize`] otherwise.
+fn visit_serialize_or_recurse(
+    backend: &mut dyn Backend,
+    value: &Value,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    match value {
+        Value::Array(array) => visit_array(backend, array, path),
+        Value::InlineTable(table) => visit_inline_table(backend, table, path),
+        scalar => visit_serialize(backend, scalar, path),
+    }
+}
+
+/// Record a table's key order in its [`ORDER_FILE`], one key per line.
+fn write_order<'a>(
+    backend: &mut dyn Backend,
+    keys: impl Iterator<Item = &'a str>,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    let mut order = String::new();
+    for key in keys {
+        order.push_str(key);
+        order.push('\n');
+    }
+    backend.write_leaf(&path.as_ref().join(ORDER_FILE), order.as_bytes())
+}
+
+/// The (prefix, suffix) trivia for an `Item` under `key`.
+///
+/// A standard `[section]` sub-table carries all its trivia on the table header
+/// decor; a scalar/array leaf keeps the leading block on the key and the
+/// trailing same-line comment on the value; nested collections keep both on the
+/// key.
+fn item_decor(key_decor: Option<&Decor>, item: &Item) -> (String, String) {
+    match item {
+        Item::Table(child) => (prefix_of(child.decor()), suffix_of(child.decor())),
+        Item::Value(value) => value_decor(key_decor, value),
+        _ => (
+            key_decor.map(prefix_of).unwrap_or_default(),
+            key_decor.map(suffix_of).unwrap_or_default(),
+        ),
+    }
+}
+
+/// The (prefix, suffix) trivia for a bare `Value`: leading block from the key,
+/// trailing same-line comment from the value.
+fn value_decor(key_decor: Option<&Decor>, value: &Value) -> (String, String) {
+    (
+        key_decor.map(prefix_of).unwrap_or_default(),
+        suffix_of(value.decor()),
+    )
+}
+
+/// The decor prefix as a string (empty when absent or non-UTF-8).
+fn prefix_of(decor: &Decor) -> String {
+    decor
+        .prefix()
+        .and_then(|raw| raw.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// The decor suffix as a string (empty when absent or non-UTF-8).
+fn suffix_of(decor: &Decor) -> String {
+    decor
+        .suffix()
+        .and_then(|raw| raw.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Capture the decor around `key` into `.meta/<key>` as a two-field TOML
+/// document (`prefix`/`suffix`) so it can be re-attached on implode. Keys with
+/// no decor are skipped to keep the exploded tree tidy.
+fn write_meta(
+    backend: &mut dyn Backend,
+    key: &str,
+    prefix: &str,
+    suffix: &str,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    if prefix.is_empty() && suffix.is_empty() {
+        return Ok(());
+    }
+
+    let mut meta = Document::new();
+    meta["prefix"] = toml_edit::value(prefix);
+    meta["suffix"] = toml_edit::value(suffix);
+    backend.write_leaf(
+        &path.as_ref().join(META_DIR).join(key),
+        meta.to_string().as_bytes(),
+    )
+}
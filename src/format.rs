@@ -0,0 +1,148 @@
+//! Cross-format document conversion.
+//!
+//! The explode/implode machinery — the backends, the visitors and the
+//! `deserialize_*` leaf parsers — is built entirely on the `toml`/`toml_edit`
+//! value model so that the comment/order fidelity from `explode` works for
+//! every format. Rather than teach every leaf a per-format encoding, this
+//! module converts JSON and YAML through a `toml_edit::Document` at the outer
+//! parse/serialize boundary only; leaf files therefore always hold TOML
+//! scalars regardless of `--doc-format`.
+//!
+//! That keeps one inference path for all formats, at the cost of the
+//! distinctions TOML's data model cannot express. The notable lossy case is
+//! JSON/YAML `null`: TOML has no null, so it is coerced to an empty string on
+//! the way in (see [`json_to_value`]) and round-trips back as `""`, not `null`.
+//!
+//! Object key order is preserved on JSON/YAML output via `serde_json`'s
+//! `preserve_order` feature (see `Cargo.toml`), so the ordering guarantee
+//! `explode` establishes for TOML holds here too.
+use crate::cli::DocFormat;
+use serde_json::{Map, Number, Value as Json};
+use toml_edit::{Document, Item, Table, Value};
+
+/// Parse a source document written in `format` into a `toml_edit::Document`.
+///
+/// TOML is parsed directly so comments, key order and value style survive a
+/// round trip (see `explode`); JSON and YAML have no such decor, so they are
+/// read through `serde_json`/`serde_yaml` into a neutral value tree and lifted
+/// into a `Document`.
+pub fn parse(format: &DocFormat, input: &str) -> anyhow::Result<Document> {
+    let doc = match format {
+        DocFormat::Toml => input.parse::<Document>()?,
+        DocFormat::Json => json_to_doc(serde_json::from_str(input)?),
+        DocFormat::Yaml => json_to_doc(serde_yaml::from_str(input)?),
+    };
+
+    Ok(doc)
+}
+
+/// Serialize an imploded `toml_edit::Document` into `format`.
+pub fn serialize(format: &DocFormat, doc: &Document) -> anyhow::Result<String> {
+    let output = match format {
+        DocFormat::Toml => doc.to_string(),
+        DocFormat::Json => serde_json::to_string_pretty(&doc_to_json(doc))?,
+        DocFormat::Yaml => serde_yaml::to_string(&doc_to_json(doc))?,
+    };
+
+    Ok(output)
+}
+
+/// Lift a neutral JSON value tree into a `toml_edit::Document`.
+fn json_to_doc(json: Json) -> Document {
+    let mut doc = Document::new();
+    if let Item::Table(table) = json_to_item(json) {
+        *doc.as_table_mut() = table;
+    }
+
+    doc
+}
+
+/// Convert a JSON value into a `toml_edit::Item`.
+fn json_to_item(json: Json) -> Item {
+    match json {
+        Json::Object(map) => {
+            let mut table = Table::new();
+            for (key, value) in map {
+                table[&key] = json_to_item(value);
+            }
+            Item::Table(table)
+        }
+        other => Item::Value(json_to_value(other)),
+    }
+}
+
+/// Convert a JSON value into a `toml_edit::Value`.
+fn json_to_value(json: Json) -> Value {
+    match json {
+        // TOML has no null; coerce to an empty string. This is lossy — a JSON
+        // `null` round-trips back as `""` — and is documented at the module level.
+        Json::Null => Value::from(""),
+        Json::Bool(boolean) => Value::from(boolean),
+        Json::Number(number) => number_to_value(number),
+        Json::String(string) => Value::from(string),
+        Json::Array(array) => Value::Array(array.into_iter().map(json_to_value).collect()),
+        Json::Object(map) => {
+            let mut inline = toml_edit::InlineTable::new();
+            for (key, value) in map {
+                inline.insert(&key, json_to_value(value));
+            }
+            Value::InlineTable(inline)
+        }
+    }
+}
+
+/// Bridge a JSON number into the `toml_edit` integer/float value model.
+fn number_to_value(number: Number) -> Value {
+    if let Some(int) = number.as_i64() {
+        Value::from(int)
+    } else {
+        // Fractional numbers, and integers that do not fit `i64`, become floats.
+        Value::from(number.as_f64().unwrap_or_default())
+    }
+}
+
+/// Convert a `toml_edit::Document` into a neutral JSON value tree.
+fn doc_to_json(doc: &Document) -> Json {
+    table_to_json(doc.as_table().iter().map(|(key, item)| (key, item_to_json(item))))
+}
+
+/// Convert a `toml_edit::Item` into a JSON value.
+fn item_to_json(item: &Item) -> Json {
+    match item {
+        Item::Value(value) => value_to_json(value),
+        Item::Table(table) => table_to_json(table.iter().map(|(k, v)| (k, item_to_json(v)))),
+        Item::ArrayOfTables(array) => {
+            Json::Array(array.iter().map(|table| table_to_json(table.iter().map(|(k, v)| (k, item_to_json(v))))).collect())
+        }
+        Item::None => Json::Null,
+    }
+}
+
+/// Convert a `toml_edit::Value` into a JSON value.
+fn value_to_json(value: &Value) -> Json {
+    match value {
+        Value::String(string) => Json::String(string.value().clone()),
+        Value::Integer(int) => Json::Number(Number::from(*int.value())),
+        Value::Float(float) => Number::from_f64(*float.value())
+            .map(Json::Number)
+            .unwrap_or(Json::Null),
+        Value::Boolean(boolean) => Json::Bool(*boolean.value()),
+        // JSON and YAML have no datetime type; fall back to the RFC 3339 text.
+        Value::Datetime(datetime) => Json::String(datetime.value().to_string()),
+        Value::Array(array) => Json::Array(array.iter().map(value_to_json).collect()),
+        Value::InlineTable(table) => {
+            table_to_json(table.iter().map(|(k, v)| (k, value_to_json(v))))
+        }
+    }
+}
+
+/// Collect key/value pairs into a JSON object, preserving their iteration order
+/// (relies on `serde_json`'s `preserve_order` feature; see `Cargo.toml`).
+fn table_to_json<'a>(entries: impl Iterator<Item = (&'a str, Json)>) -> Json {
+    let mut map = Map::new();
+    for (key, value) in entries {
+        map.insert(key.to_string(), value);
+    }
+
+    Json::Object(map)
+}
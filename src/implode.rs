@@ -1,34 +1,306 @@
+use crate::backend::Backend;
+use crate::explode::{ARRAY_SENTINEL, META_DIR, ORDER_FILE, TABLE_SENTINEL, TRAILER_FILE};
 use anyhow::anyhow;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{
-    fs::{self, DirEntry},
-    path::Path,
-    str::FromStr,
-};
-
-/// Deserialize path of files/folders into a `toml::Value`
-pub fn deserialize_any(path: impl AsRef<Path>) -> anyhow::Result<toml::Value> {
-    let attr = fs::metadata(&path)?;
-    if attr.is_file() {
-        let contents = fs::read_to_string(&path)?;
-        // newlines are often inserted when writing files by hand or using `echo` in shell.
-        let contents = contents.trim_end();
-        let value = deserialize_bool(&contents)
-            .or_else(|_| deserialize_str(&contents))
-            .or_else(|_| deserialize_i64(&contents))
-            .or_else(|_| deserialize_f64(&contents))
-            .or_else(|_| deserialize_datetime(&contents))
-            .map_err(|_| anyhow!("Could not parse TOML value for file {:?}", &path.as_ref()));
-        Ok(value?)
-    } else if attr.is_dir() {
-        let files = fs::read_dir(&path)?
-            .filter_map(|entry| entry.ok())
-            .collect();
-
-        deserialize_array(&files).or_else(|_| deserialize_table(&files))
+use std::{path::Path, path::PathBuf, str::FromStr};
+use toml_edit::{Array, ArrayOfTables, Datetime, Document, Item, Table, Value};
+
+/// Rebuild a filesystem (or archive) layout into a format-preserving
+/// `toml_edit::Document`.
+///
+/// The value tree itself is produced by [`deserialize_any`]; this layer walks
+/// the same tree a second time to re-attach the decor captured in `.meta/<key>`
+/// and to honour the key order recorded in `.order`, so an explode/implode
+/// cycle reproduces comments, blank lines and key ordering.
+pub fn walk(backend: &dyn Backend, path: impl AsRef<Path>) -> anyhow::Result<Document> {
+    let value = deserialize_any(backend, &path)?;
+    let mut doc = Document::new();
+    match decorate(backend, &path, value)? {
+        Item::Table(table) => *doc.as_table_mut() = table,
+        item => return Err(anyhow!("Root of {:?} is not a table: {:?}", path.as_ref(), item)),
+    }
+    // Restore the document trailer captured alongside the root table.
+    if let Ok(bytes) = backend.read_file(&path.as_ref().join(TRAILER_FILE)) {
+        doc.set_trailing(String::from_utf8(bytes)?);
+    }
+
+    Ok(doc)
+}
+
+/// Re-attach the decor/order sidecars produced during explode to the value tree
+/// returned by [`deserialize_any`], yielding a `toml_edit::Item`.
+fn decorate(
+    backend: &dyn Backend,
+    path: impl AsRef<Path>,
+    value: toml::Value,
+) -> anyhow::Result<Item> {
+    match value {
+        toml::Value::Table(table) => decorate_table(backend, &path, table).map(Item::Table),
+        toml::Value::Array(array) => decorate_array(backend, &path, array),
+        scalar => Ok(Item::Value(into_edit_value(scalar)?)),
+    }
+}
+
+/// Rebuild a `toml_edit::Table`, inserting keys in the order recorded in the
+/// directory's `.order` sidecar and restoring each key's decor from `.meta`.
+fn decorate_table(
+    backend: &dyn Backend,
+    path: impl AsRef<Path>,
+    mut table: toml::value::Table,
+) -> anyhow::Result<Table> {
+    let mut edit = Table::new();
+    for key in order(backend, &path, table.keys()) {
+        let child = table
+            .remove(&key)
+            .ok_or_else(|| anyhow!("`.order` lists unknown key {:?}", key))?;
+        edit[key.as_str()] = decorate(backend, path.as_ref().join(&key), child)?;
+        if let Some((prefix, suffix)) = read_meta(backend, &path, &key)? {
+            // Restore the decor to wherever explode captured it (see
+            // `explode::item_decor`): a `[section]` header for tables, the value
+            // suffix plus key prefix for leaves, the key for everything else.
+            match edit.get_mut(key.as_str()) {
+                Some(Item::Table(child)) => {
+                    let decor = child.decor_mut();
+                    decor.set_prefix(prefix);
+                    decor.set_suffix(suffix);
+                }
+                Some(Item::Value(value)) => {
+                    value.decor_mut().set_suffix(suffix);
+                    edit.key_decor_mut(key.as_str())
+                        .expect("key was just inserted")
+                        .set_prefix(prefix);
+                }
+                _ => {
+                    let decor = edit
+                        .key_decor_mut(key.as_str())
+                        .expect("key was just inserted");
+                    decor.set_prefix(prefix);
+                    decor.set_suffix(suffix);
+                }
+            }
+        }
+    }
+
+    Ok(edit)
+}
+
+/// Rebuild an array. A directory whose elements are all tables becomes an
+/// `[[array of tables]]`; otherwise an inline array of values.
+fn decorate_array(
+    backend: &dyn Backend,
+    path: impl AsRef<Path>,
+    array: toml::value::Array,
+) -> anyhow::Result<Item> {
+    let items = array
+        .into_iter()
+        .enumerate()
+        .map(|(i, child)| decorate(backend, path.as_ref().join(i.to_string()), child))
+        .collect::<anyhow::Result<Vec<Item>>>()?;
+
+    if !items.is_empty() && items.iter().all(|item| item.is_table()) {
+        let mut tables = ArrayOfTables::new();
+        for item in items {
+            tables.push(item.into_table().expect("checked above"));
+        }
+        Ok(Item::ArrayOfTables(tables))
     } else {
-        Err(anyhow!("Not a file or a dictory: {:?}", &path.as_ref()))
+        // A non-uniform array (e.g. `[1, {a = 2}]`) cannot be an array-of-tables,
+        // so any table element is folded into an inline table rather than erroring.
+        let mut values = Array::new();
+        for item in items {
+            values.push(into_inline_value(item)?);
+        }
+        Ok(Item::Value(Value::Array(values)))
+    }
+}
+
+/// Coerce an `Item` into an inline `Value`, so it can live inside an inline
+/// array. Standard and array-of-tables items are rewritten as their inline
+/// equivalents.
+fn into_inline_value(item: Item) -> anyhow::Result<Value> {
+    match item {
+        Item::Value(value) => Ok(value),
+        Item::Table(table) => Ok(Value::InlineTable(table.into_inline_table())),
+        Item::ArrayOfTables(tables) => {
+            let mut array = Array::new();
+            for table in tables.iter() {
+                array.push(Value::InlineTable(table.clone().into_inline_table()));
+            }
+            Ok(Value::Array(array))
+        }
+        Item::None => Err(anyhow!("Array element is missing")),
+    }
+}
+
+/// Bridge a scalar `toml::Value` into the `toml_edit` value model.
+fn into_edit_value(value: toml::Value) -> anyhow::Result<Value> {
+    let edit = match value {
+        toml::Value::String(string) => Value::from(string),
+        toml::Value::Integer(int) => Value::from(int),
+        toml::Value::Float(float) => Value::from(float),
+        toml::Value::Boolean(boolean) => Value::from(boolean),
+        // `toml` and `toml_edit` use distinct `Datetime` types; bridge them via
+        // the RFC 3339 text form that both round-trip.
+        toml::Value::Datetime(datetime) => Value::from(Datetime::from_str(&datetime.to_string())?),
+        other => return Err(anyhow!("Not a scalar value: {:?}", other)),
+    };
+
+    Ok(edit)
+}
+
+/// Resolve a table's key order, preferring the `.order` sidecar and falling
+/// back to the value's own iteration order when it is absent.
+fn order<'a>(
+    backend: &dyn Backend,
+    path: impl AsRef<Path>,
+    keys: impl Iterator<Item = &'a String>,
+) -> Vec<String> {
+    match backend.read_file(&path.as_ref().join(ORDER_FILE)) {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).lines().map(String::from).collect(),
+        Err(_) => keys.cloned().collect(),
+    }
+}
+
+/// Read the `prefix`/`suffix` decor recorded for `key` in `.meta/<key>`.
+fn read_meta(
+    backend: &dyn Backend,
+    path: impl AsRef<Path>,
+    key: &str,
+) -> anyhow::Result<Option<(String, String)>> {
+    let meta_path = path.as_ref().join(META_DIR).join(key);
+    let bytes = match backend.read_file(&meta_path) {
+        Ok(bytes) => bytes,
+        Err(_) => return Ok(None),
+    };
+    let doc = String::from_utf8(bytes)?.parse::<Document>()?;
+    let decor = |field| {
+        doc.get(field)
+            .and_then(Item::as_str)
+            .unwrap_or("")
+            .to_string()
+    };
+
+    Ok(Some((decor("prefix"), decor("suffix"))))
+}
+
+/// Parse `contents` with the leaf parser named by a type-tag extension,
+/// returning `None` when the extension is not a recognised tag.
+fn deserialize_tagged(tag: &str, contents: &str) -> Option<anyhow::Result<toml::Value>> {
+    match tag {
+        "bool" => Some(deserialize_bool(contents)),
+        "str" => Some(deserialize_str(contents)),
+        "int" => Some(deserialize_i64(contents)),
+        "float" => Some(deserialize_f64(contents)),
+        "datetime" => Some(deserialize_datetime(contents)),
+        _ => None,
+    }
+}
+
+/// Whether `ext` is a recognised leaf type-tag extension.
+fn is_type_tag(ext: &str) -> bool {
+    matches!(ext, "bool" | "str" | "int" | "float" | "datetime")
+}
+
+/// The logical key/index for a path, stripping a recognised type-tag extension
+/// (`foo.str` -> `foo`) but leaving ordinary dotted names (`config.prod`)
+/// untouched.
+///
+/// `explode` only tags scalar leaf files, never collection directories, so a
+/// directory whose key happens to end in `.int`/`.str`/... keeps its full name;
+/// stripping it there would mis-key the sub-table and desync it from `.order`.
+fn logical_name(backend: &dyn Backend, path: &Path) -> Option<String> {
+    let is_leaf = backend.read_dir(path).is_err();
+    let is_tag = is_leaf
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(is_type_tag)
+            .unwrap_or(false);
+    if is_tag {
+        path.file_stem().and_then(|stem| stem.to_str()).map(String::from)
+    } else {
+        path.file_name().and_then(|name| name.to_str()).map(String::from)
+    }
+}
+
+/// Collection kind recorded by an explode sentinel.
+enum Kind {
+    Array,
+    Table,
+}
+
+/// Inspect a directory's entries for an `.array`/`.table` sentinel.
+fn sentinel_kind(children: &[PathBuf]) -> Option<Kind> {
+    let named = |sentinel: &str| {
+        children
+            .iter()
+            .any(|child| child.file_name().and_then(|name| name.to_str()) == Some(sentinel))
+    };
+    if named(ARRAY_SENTINEL) {
+        Some(Kind::Array)
+    } else if named(TABLE_SENTINEL) {
+        Some(Kind::Table)
+    } else {
+        None
+    }
+}
+
+/// Whether a path is one of explode's hidden bookkeeping sidecars (`.order`,
+/// `.meta`, ...), which must be skipped when inferring a collection's shape.
+fn is_sidecar(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with('.'))
+        .unwrap_or(false)
+}
+
+/// Deserialize a path of files/folders into a `toml::Value`
+pub fn deserialize_any(backend: &dyn Backend, path: impl AsRef<Path>) -> anyhow::Result<toml::Value> {
+    match backend.read_dir(path.as_ref()) {
+        Ok(children) => {
+            let kind = sentinel_kind(&children);
+            let files: Vec<PathBuf> = children
+                .into_iter()
+                .filter(|child| !is_sidecar(child))
+                .collect();
+
+            match kind {
+                // An explicit sentinel pins the collection kind (and yields the
+                // correct empty collection when nothing else is present).
+                Some(Kind::Array) if files.is_empty() => Ok(toml::Value::Array(Vec::new())),
+                Some(Kind::Array) => deserialize_array(backend, &files),
+                Some(Kind::Table) => deserialize_table(backend, &files),
+                // No sentinel: fall back to the entry-name heuristic so
+                // hand-built trees still work.
+                None => {
+                    deserialize_array(backend, &files).or_else(|_| deserialize_table(backend, &files))
+                }
+            }
+        }
+        Err(_) => {
+            let bytes = backend.read_file(path.as_ref())?;
+            let contents = String::from_utf8(bytes)?;
+            // newlines are often inserted when writing files by hand or using `echo` in shell.
+            let contents = contents.trim_end();
+            // An explicit type-tag extension pins the type; otherwise fall back
+            // to inference for backward compatibility with hand-built trees.
+            if let Some(result) = path
+                .as_ref()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(|ext| deserialize_tagged(ext, contents))
+            {
+                return result
+                    .map_err(|_| anyhow!("Could not parse TOML value for file {:?}", path.as_ref()));
+            }
+            deserialize_bool(contents)
+                .or_else(|_| deserialize_str(contents))
+                .or_else(|_| deserialize_i64(contents))
+                .or_else(|_| deserialize_f64(contents))
+                .or_else(|_| deserialize_datetime(contents))
+                .map_err(|_| anyhow!("Could not parse TOML value for file {:?}", path.as_ref()))
+        }
     }
 }
 
@@ -51,10 +323,13 @@ pub fn deserialize_bool(input: impl AsRef<str>) -> anyhow::Result<toml::Value> {
 /// use explodesh::implode;
 ///
 /// assert_eq!(toml::Value::String(String::from("foo")), implode::deserialize_str("\"foo\"").unwrap());
+/// assert_eq!(toml::Value::String(String::new()), implode::deserialize_str("\"\"").unwrap());
 /// ```
 pub fn deserialize_str(input: impl AsRef<str>) -> anyhow::Result<toml::Value> {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r#"^"(.+)"$"#).unwrap();
+        // `.*` (not `.+`) so the empty string `""` is a valid leaf, not a parse
+        // error — see the empty-string/null round-trip tests.
+        static ref RE: Regex = Regex::new(r#"^"(.*)"$"#).unwrap();
     }
     match RE.captures(&input.as_ref().parse::<String>()?) {
         Some(captures) => {
@@ -108,39 +383,39 @@ pub fn deserialize_datetime(input: impl AsRef<str>) -> anyhow::Result<toml::Valu
 /// ```
 /// use std::fs;
 /// use tempfile::TempDir;
-/// use explodesh::implode;
+/// use explodesh::{backend::DirBackend, implode};
 ///
 /// let tmp_dir = TempDir::new().unwrap();
 /// fs::write(tmp_dir.path().join("0"), "true");
 /// fs::write(tmp_dir.path().join("1"), "23");
 /// fs::write(tmp_dir.path().join("2"), r#""hello""#);
-/// let files = fs::read_dir(tmp_dir.path()).unwrap().filter_map(|entry| entry.ok()).collect();
-/// let value = implode::deserialize_array(&files).unwrap();
+/// let files = vec![tmp_dir.path().join("0"), tmp_dir.path().join("1"), tmp_dir.path().join("2")];
+/// let value = implode::deserialize_array(&DirBackend, &files).unwrap();
 ///
 /// assert_eq!(value[0], toml::Value::Boolean(true));
 /// assert_eq!(value[1], toml::Value::Integer(23));
 /// assert_eq!(value[2], toml::Value::String(String::from("hello")));
 /// ```
-pub fn deserialize_array(files: &Vec<DirEntry>) -> anyhow::Result<toml::Value> {
+pub fn deserialize_array(backend: &dyn Backend, files: &[PathBuf]) -> anyhow::Result<toml::Value> {
     // Array validation is made up of two parts:
     // * that all the files in the folder are unsigned integers
     // * that they are sequentially ordered starting from 0 with no duplicates
-    let mut indexed_files: Vec<(usize, &DirEntry)> = files
+    if files.is_empty() {
+        return Err(anyhow!("Empty directory is not an array"));
+    }
+    let mut indexed_files: Vec<(usize, &PathBuf)> = files
         .iter()
-        .map(|entry| {
+        .map(|path| {
             // these unwraps are checked before when generating indexes
-            entry
-                .file_name()
-                .as_os_str()
-                .to_str()
-                .unwrap_or("Not valid UTF-8")
+            logical_name(backend, path)
+                .unwrap_or_else(|| String::from("Not valid UTF-8"))
                 .parse::<usize>()
                 .map_err(|_| "Invalid Unsigned Integer")
-                .map(|filename| (filename, entry))
+                .map(|filename| (filename, path))
         })
-        .collect::<Result<Vec<(usize, &DirEntry)>, &'static str>>()
+        .collect::<Result<Vec<(usize, &PathBuf)>, &'static str>>()
         .map_err(|err| anyhow!(err))?;
-    indexed_files.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+    indexed_files.sort_by_key(|(key, _)| *key);
 
     let mut indexes: Vec<&usize> = indexed_files.iter().map(|(key, _)| key).collect();
     indexes.dedup();
@@ -150,8 +425,8 @@ pub fn deserialize_array(files: &Vec<DirEntry>) -> anyhow::Result<toml::Value> {
     {
         let array = indexed_files
             .iter()
-            .map(|(_, entry)| deserialize_any(&entry.path()).unwrap())
-            .collect::<Vec<toml::value::Value>>();
+            .map(|(_, path)| deserialize_any(backend, path))
+            .collect::<anyhow::Result<Vec<toml::value::Value>>>()?;
 
         Ok(toml::Value::Array(array))
     } else {
@@ -164,29 +439,23 @@ pub fn deserialize_array(files: &Vec<DirEntry>) -> anyhow::Result<toml::Value> {
 /// ```
 /// use std::fs;
 /// use tempfile::TempDir;
-/// use explodesh::implode;
+/// use explodesh::{backend::DirBackend, implode};
 ///
 /// let tmp_dir = TempDir::new().unwrap();
 /// fs::write(tmp_dir.path().join("foo"), r#""bar""#);
 /// fs::write(tmp_dir.path().join("0"), "42");
-/// let files = fs::read_dir(tmp_dir.path()).unwrap().filter_map(|entry| entry.ok()).collect();
-/// let value = implode::deserialize_table(&files).unwrap();
+/// let files = vec![tmp_dir.path().join("foo"), tmp_dir.path().join("0")];
+/// let value = implode::deserialize_table(&DirBackend, &files).unwrap();
 ///
 /// assert_eq!(value.get("foo"), Some(&toml::Value::String(String::from("bar"))));
 /// assert_eq!(value.get("0"), Some(&toml::Value::Integer(42)));
 /// ```
-pub fn deserialize_table(files: &Vec<DirEntry>) -> anyhow::Result<toml::Value> {
+pub fn deserialize_table(backend: &dyn Backend, files: &[PathBuf]) -> anyhow::Result<toml::Value> {
     let mut table = toml::value::Table::new();
-    for entry in files.iter() {
-        // this unwrap is handled by everything being a valid DirEntry
-        let key = String::from(
-            entry
-                .file_name()
-                .as_os_str()
-                .to_str()
-                .ok_or(anyhow!("Invalid UTF-8 characters in filename"))?,
-        );
-        table.insert(key, deserialize_any(entry.path())?);
+    for path in files.iter() {
+        let key = logical_name(backend, path)
+            .ok_or_else(|| anyhow!("Invalid UTF-8 characters in filename"))?;
+        table.insert(key, deserialize_any(backend, path)?);
     }
     Ok(toml::Value::Table(table))
 }
@@ -194,6 +463,7 @@ pub fn deserialize_table(files: &Vec<DirEntry>) -> anyhow::Result<toml::Value> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backend::DirBackend;
     use std::fs;
     use tempfile::TempDir;
 
@@ -205,7 +475,7 @@ mod tests {
             r#""foo"
 "#,
         )?;
-        assert!(deserialize_any(tmp_dir.path()).is_ok());
+        assert!(deserialize_any(&DirBackend, tmp_dir.path()).is_ok());
 
         Ok(())
     }
@@ -215,11 +485,9 @@ mod tests {
         let tmp_dir = TempDir::new()?;
         fs::write(tmp_dir.path().join("0"), "true")?;
         fs::write(tmp_dir.path().join("2"), "false")?;
-        let files = fs::read_dir(tmp_dir.path())?
-            .filter_map(|entry| entry.ok())
-            .collect();
+        let files = vec![tmp_dir.path().join("0"), tmp_dir.path().join("2")];
 
-        assert!(deserialize_array(&files).is_err());
+        assert!(deserialize_array(&DirBackend, &files).is_err());
 
         Ok(())
     }
@@ -234,11 +502,12 @@ mod tests {
                 toml::to_string(value)?,
             )?;
         }
-        let files = fs::read_dir(tmp_dir.path())?
-            .filter_map(|entry| entry.ok())
-            .collect();
+        let files = entries
+            .iter()
+            .map(|(index, _)| tmp_dir.path().join(index.to_string()))
+            .collect::<Vec<_>>();
 
-        let array = deserialize_array(&files)?;
+        let array = deserialize_array(&DirBackend, &files)?;
         for (index, value) in entries.iter() {
             assert_eq!(array[index], toml::Value::String(value.to_string()));
         }
@@ -246,12 +515,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn type_tag_extension_overrides_inference() -> anyhow::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        // `1.0` would infer as a float, but the `.str` tag pins it as a string.
+        fs::write(tmp_dir.path().join("version.str"), r#""1.0""#)?;
+        fs::write(tmp_dir.path().join("port.int"), "8080")?;
+
+        let value = deserialize_any(&DirBackend, tmp_dir.path())?;
+        let table = value.as_table().unwrap();
+        assert_eq!(
+            table.get("version"),
+            Some(&toml::Value::String(String::from("1.0")))
+        );
+        assert_eq!(table.get("port"), Some(&toml::Value::Integer(8080)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_tag_extension_is_not_stripped_from_directories() -> anyhow::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        // A sub-table whose key ends in a type tag must keep its full name: the
+        // tag only applies to scalar leaf files.
+        let inner = tmp_dir.path().join("a.int");
+        fs::create_dir(&inner)?;
+        fs::write(inner.join("port.int"), "8080")?;
+
+        let value = deserialize_any(&DirBackend, tmp_dir.path())?;
+        let table = value.as_table().unwrap();
+        assert!(table.get("a.int").is_some());
+        assert!(table.get("a").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn table_sentinel_beats_array_heuristic() -> anyhow::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        // Contiguous integer keys would infer as an array, but the `.table`
+        // sentinel pins the directory as a table.
+        fs::write(tmp_dir.path().join("0"), r#""a""#)?;
+        fs::write(tmp_dir.path().join("1"), r#""b""#)?;
+        fs::write(tmp_dir.path().join(TABLE_SENTINEL), "")?;
+
+        let value = deserialize_any(&DirBackend, tmp_dir.path())?;
+        assert!(value.is_table());
+        assert_eq!(
+            value.as_table().unwrap().get("0"),
+            Some(&toml::Value::String(String::from("a")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_sentinel_yields_empty_array() -> anyhow::Result<()> {
+        let tmp_dir = TempDir::new()?;
+        fs::write(tmp_dir.path().join(ARRAY_SENTINEL), "")?;
+
+        let value = deserialize_any(&DirBackend, tmp_dir.path())?;
+        assert_eq!(value, toml::Value::Array(Vec::new()));
+
+        Ok(())
+    }
+
     #[test]
     fn deserialize_any_simple() -> anyhow::Result<()> {
         let tmp_dir = TempDir::new()?;
         fs::write(tmp_dir.path().join("foo"), r#""bar""#)?;
 
-        let value = deserialize_any(tmp_dir.path())?;
+        let value = deserialize_any(&DirBackend, tmp_dir.path())?;
         assert_eq!(
             value.as_table().unwrap().get("foo").unwrap(),
             &toml::Value::String(String::from("bar"))
@@ -0,0 +1,5 @@
+pub mod backend;
+pub mod cli;
+pub mod explode;
+pub mod format;
+pub mod implode;
@@ -1,9 +1,10 @@
-use clap::Clap;
+use clap::Parser;
 use explodesh::{
-    cli::{Cli, Command},
-    explode, implode,
+    backend::{Backend, DirBackend, TarBackend},
+    cli::{Cli, Command, Format},
+    explode, format, implode,
 };
-use std::{fs, path::PathBuf};
+use std::{fs, fs::File, path::PathBuf};
 
 fn main() -> anyhow::Result<()> {
     let opts: Cli = Cli::parse();
@@ -13,12 +14,25 @@ fn main() -> anyhow::Result<()> {
 
     match opts.cmd {
         Command::Explode => {
-            let doc = toml::from_str(&fs::read_to_string(source)?)?;
-            explode::visit_value(&doc, destination)?;
+            let doc = format::parse(&opts.doc_format, &fs::read_to_string(source)?)?;
+            match opts.format {
+                Format::Dir => {
+                    explode::visit_document(&mut DirBackend, &doc, destination)?;
+                }
+                Format::Tar => {
+                    let mut backend = TarBackend::new(&destination);
+                    explode::visit_document(&mut backend, &doc, &destination)?;
+                    backend.into_writer(File::create(&destination)?)?;
+                }
+            }
         }
         Command::Implode => {
-            let doc = implode::walk(&source)?;
-            fs::write(&destination, toml::to_string(&doc)?)?;
+            let backend: Box<dyn Backend> = match opts.format {
+                Format::Dir => Box::new(DirBackend),
+                Format::Tar => Box::new(TarBackend::from_reader(&source, File::open(&source)?)?),
+            };
+            let doc = implode::walk(backend.as_ref(), &source)?;
+            fs::write(&destination, format::serialize(&opts.doc_format, &doc)?)?;
         }
     }
 
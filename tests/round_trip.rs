@@ -0,0 +1,116 @@
+//! End-to-end explode -> implode round trips across both backends and every
+//! document format. These exercise the whole pipeline (`visit_document` ->
+//! `walk`) rather than the individual leaf parsers, which is where the
+//! empty-string/null and heterogeneous-array regressions slipped through.
+
+use explodesh::backend::{DirBackend, TarBackend};
+use explodesh::cli::DocFormat;
+use explodesh::{explode, format, implode};
+use std::io::Cursor;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Explode `toml_src` to a directory and implode it back, returning the
+/// rendered TOML.
+fn round_trip_dir(toml_src: &str) -> String {
+    let doc = format::parse(&DocFormat::Toml, toml_src).unwrap();
+    let dir = TempDir::new().unwrap();
+    explode::visit_document(&mut DirBackend, &doc, dir.path()).unwrap();
+    implode::walk(&DirBackend, dir.path()).unwrap().to_string()
+}
+
+/// Explode `toml_src` into a tar archive and implode it back, returning the
+/// rendered TOML.
+fn round_trip_tar(toml_src: &str) -> String {
+    let root = Path::new("config");
+    let doc = format::parse(&DocFormat::Toml, toml_src).unwrap();
+    let mut backend = TarBackend::new(root);
+    explode::visit_document(&mut backend, &doc, root).unwrap();
+    let mut buf = Vec::new();
+    backend.into_writer(&mut buf).unwrap();
+    let reader = TarBackend::from_reader(root, Cursor::new(buf)).unwrap();
+    implode::walk(&reader, root).unwrap().to_string()
+}
+
+#[test]
+fn dir_round_trip_preserves_comments_order_and_trailer() {
+    let src = "\
+# leading comment
+b = 1
+a = \"two\" # trailing comment
+
+[section]
+# before key
+x = true
+
+# document trailer
+";
+    assert_eq!(round_trip_dir(src), src);
+}
+
+#[test]
+fn tar_round_trip_matches_dir() {
+    let src = "\
+name = \"explodesh\"
+ports = [1, 2, 3]
+
+[owner]
+handle = \"hone\"
+";
+    assert_eq!(round_trip_tar(src), src);
+    assert_eq!(round_trip_tar(src), round_trip_dir(src));
+}
+
+#[test]
+fn empty_string_leaf_round_trips() {
+    let src = "empty = \"\"\n";
+    assert_eq!(round_trip_dir(src), src);
+    assert_eq!(round_trip_tar(src), src);
+}
+
+#[test]
+fn heterogeneous_array_round_trips() {
+    let src = "mixed = [1, { a = 2 }]\n";
+    // Value-equal after a round trip (formatting of the inline table may differ).
+    let rebuilt: toml::Value = toml::from_str(&round_trip_dir(src)).unwrap();
+    let original: toml::Value = toml::from_str(src).unwrap();
+    assert_eq!(rebuilt, original);
+}
+
+/// Parse `input` in `format`, round-trip it through a directory, and re-serialize
+/// in the same format.
+fn round_trip_doc(format: &DocFormat, input: &str) -> String {
+    let doc = format::parse(format, input).unwrap();
+    let dir = TempDir::new().unwrap();
+    explode::visit_document(&mut DirBackend, &doc, dir.path()).unwrap();
+    let rebuilt = implode::walk(&DirBackend, dir.path()).unwrap();
+    format::serialize(format, &rebuilt).unwrap()
+}
+
+#[test]
+fn json_round_trips_value_equal() {
+    let input = r#"{"name":"explodesh","ports":[1,2,3],"owner":{"handle":"hone"}}"#;
+    let out = round_trip_doc(&DocFormat::Json, input);
+    let rebuilt: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let original: serde_json::Value = serde_json::from_str(input).unwrap();
+    assert_eq!(rebuilt, original);
+}
+
+#[test]
+fn yaml_round_trips_value_equal() {
+    let input = "name: explodesh\nports:\n- 1\n- 2\n- 3\n";
+    let out = round_trip_doc(&DocFormat::Yaml, input);
+    let rebuilt: serde_yaml::Value = serde_yaml::from_str(&out).unwrap();
+    let original: serde_yaml::Value = serde_yaml::from_str(input).unwrap();
+    assert_eq!(rebuilt, original);
+}
+
+#[test]
+fn json_null_round_trips_to_empty_string() {
+    // TOML has no null: the documented lossy behaviour is that a JSON null
+    // comes back as an empty string — but it must not error (see module docs
+    // on `format`).
+    let out = round_trip_doc(&DocFormat::Json, r#"{"missing":null}"#);
+    let rebuilt: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(rebuilt, serde_json::json!({ "missing": "" }));
+}